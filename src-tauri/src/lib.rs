@@ -1,8 +1,40 @@
-// Tauri command für direktes Clipboard-Schreiben
+#[cfg(target_os = "macos")]
+mod macos_ax;
+#[cfg(target_os = "windows")]
+mod windows_ax;
+#[cfg(target_os = "linux")]
+mod linux_clipboard;
+
+/// Ergebnis einer plattformübergreifenden Selection-Erfassung, inklusive der
+/// verwendeten Methode, damit das Frontend nachvollziehen kann, ob die
+/// native Accessibility-API oder der Clipboard-Fallback gegriffen hat.
+#[derive(Debug, Clone, serde::Serialize)]
+struct SelectionResult {
+    text: String,
+    method: String,
+}
+
+// Tauri command für direktes Clipboard-Schreiben. Auf Linux kann über
+// `primary: true` statt der regulären Zwischenablage die PRIMARY-Selection
+// angesprochen werden (pluggable Backend aus `linux_clipboard`).
 #[tauri::command]
-fn copy_to_clipboard(text: String, app_handle: tauri::AppHandle) -> Result<String, String> {
+fn copy_to_clipboard(text: String, app_handle: tauri::AppHandle, primary: Option<bool>) -> Result<String, String> {
+    #[cfg(target_os = "linux")]
+    {
+        if primary.unwrap_or(false) {
+            return linux_clipboard::detect_provider()
+                .set_contents(text.clone(), linux_clipboard::ClipboardType::Selection)
+                .map(|_| {
+                    println!("✅ Text in PRIMARY-Selection kopiert: {} Zeichen", text.len());
+                    format!("Text in PRIMARY-Selection kopiert: {} Zeichen", text.len())
+                });
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = primary;
+
     use tauri_plugin_clipboard_manager::ClipboardExt;
-    
+
     match app_handle.clipboard().write_text(text.clone()) {
         Ok(_) => {
             println!("✅ Text erfolgreich in Zwischenablage kopiert: {} Zeichen", text.len());
@@ -15,11 +47,32 @@ fn copy_to_clipboard(text: String, app_handle: tauri::AppHandle) -> Result<Strin
     }
 }
 
-// Tauri command für Clipboard-Lesen
+// Tauri command für Clipboard-Lesen. Auf Linux kann über `primary: true` statt
+// der regulären Zwischenablage die PRIMARY-Selection gelesen werden, in der
+// markierter Text ohne explizites Kopieren landet.
 #[tauri::command]
-fn read_clipboard(app_handle: tauri::AppHandle) -> Result<String, String> {
+fn read_clipboard(app_handle: tauri::AppHandle, primary: Option<bool>) -> Result<String, String> {
+    #[cfg(target_os = "linux")]
+    {
+        if primary.unwrap_or(false) {
+            return linux_clipboard::detect_provider()
+                .get_contents(linux_clipboard::ClipboardType::Selection)
+                .and_then(|text| {
+                    if text.trim().is_empty() {
+                        println!("ℹ️ Keine Textdaten in PRIMARY-Selection");
+                        Err("Keine Textdaten in PRIMARY-Selection".to_string())
+                    } else {
+                        println!("✅ Text aus PRIMARY-Selection gelesen: {} Zeichen", text.len());
+                        Ok(text)
+                    }
+                });
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = primary;
+
     use tauri_plugin_clipboard_manager::ClipboardExt;
-    
+
     match app_handle.clipboard().read_text() {
         Ok(text) => {
             if text.trim().is_empty() {
@@ -37,30 +90,81 @@ fn read_clipboard(app_handle: tauri::AppHandle) -> Result<String, String> {
     }
 }
 
+// Liefert den Namen des Prozesses, der gerade im Vordergrund ist. Wird benutzt,
+// um Auto-Copy zu überspringen, wenn die App sich selbst "abkopieren" würde.
+#[cfg(target_os = "macos")]
+fn frontmost_process_name() -> Result<String, String> {
+    match std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(r#"tell application "System Events" to get name of first process whose frontmost is true"#)
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        Ok(output) => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        Err(e) => Err(format!("{:?}", e)),
+    }
+}
+
+// Prüft, ob der übergebene Prozessname unser eigener App-Name ist. `System
+// Events` meldet den Anzeigenamen des Bundles, also `productName` aus
+// `tauri.conf.json` (z.B. "MeinWort-Pro") – das weicht vom Cargo-Package-Namen
+// (`package_info().name`, z.B. "meinwort-pro") häufig ab. Deshalb zuerst gegen
+// `productName` prüfen und nur auf den Package-Namen zurückfallen, wenn dieser
+// nicht gesetzt ist.
+#[cfg(target_os = "macos")]
+fn is_own_app_name(app_handle: &tauri::AppHandle, name: &str) -> bool {
+    use tauri::Manager;
+
+    match app_handle.config().product_name.as_deref() {
+        Some(product_name) => name.eq_ignore_ascii_case(product_name),
+        None => name.eq_ignore_ascii_case(&app_handle.package_info().name),
+    }
+}
+
 // Tauri command um markierten Text automatisch zu kopieren
 #[tauri::command]
-fn auto_copy_selection() -> Result<String, String> {
+fn auto_copy_selection(app_handle: tauri::AppHandle) -> Result<String, String> {
     println!("📋 Auto-copying currently selected text...");
-    
+
     #[cfg(target_os = "macos")]
     {
-        // macOS: Erweiterte Lösung mit Delay für bessere Erkennung
+        // Nicht unsere eigene Selection "abkopieren", wenn die App selbst im Vordergrund ist
+        match frontmost_process_name() {
+            Ok(name) if is_own_app_name(&app_handle, &name) => {
+                println!("ℹ️ App ist selbst im Vordergrund, überspringe Auto-Copy");
+                return Err("App ist im Vordergrund".to_string());
+            }
+            Ok(_) => {}
+            Err(e) => println!("⚠️ Konnte Vordergrund-App nicht ermitteln: {}", e),
+        }
+
+        // Bestehende Zwischenablage sichern, damit wir sie nach dem Cmd+C wiederherstellen können
+        let saved_clipboard = read_clipboard(app_handle.clone(), None).ok();
+
+        // macOS: Erweiterte Lösung mit Delay für bessere Erkennung. Die Alert-Lautstärke
+        // wird während des Keystrokes stummgeschaltet, damit macOS nicht den "funk"-Sound
+        // abspielt, wenn das fokussierte Element nichts zu kopieren hat.
         let script = r#"
             tell application "System Events"
-                -- Ultra-Speed: Minimal delays für maximale Responsiveness  
+                set savedAlertVolume to alert volume of (get volume settings)
+                set volume alert volume 0
+                -- Ultra-Speed: Minimal delays für maximale Responsiveness
                 delay 0.01
                 -- Cmd+C senden
                 keystroke "c" using {command down}
                 -- Ultra-Speed: Minimal wait für Copy
                 delay 0.02
+                set volume alert volume savedAlertVolume
                 -- Erfolg zurückgeben
                 return "success"
             end tell
         "#;
-        
+
         println!("🔄 Sende Cmd+C via AppleScript...");
-        
-        match std::process::Command::new("osascript")
+
+        let result = match std::process::Command::new("osascript")
             .arg("-e")
             .arg(script)
             .output()
@@ -68,15 +172,21 @@ fn auto_copy_selection() -> Result<String, String> {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout);
                 let stderr = String::from_utf8_lossy(&output.stderr);
-                
+
                 println!("📤 AppleScript stdout: {}", stdout);
                 if !stderr.is_empty() {
                     println!("⚠️ AppleScript stderr: {}", stderr);
                 }
-                
+
                 if output.status.success() {
                     println!("✅ Successfully sent Cmd+C on macOS");
-                    Ok("Markierter Text automatisch kopiert".to_string())
+                    read_clipboard(app_handle.clone(), None).and_then(|text| {
+                        if saved_clipboard.as_deref() == Some(text.as_str()) {
+                            Err("Keine Selection vorhanden, Zwischenablage unverändert".to_string())
+                        } else {
+                            Ok(text)
+                        }
+                    })
                 } else {
                     println!("⚠️ AppleScript failed with exit code: {:?}", output.status.code());
                     Err("AppleScript Fehler beim automatischen Kopieren".to_string())
@@ -86,18 +196,29 @@ fn auto_copy_selection() -> Result<String, String> {
                 println!("❌ AppleScript command failed: {:?}", e);
                 Err(format!("Fehler beim automatischen Kopieren: {}", e))
             }
+        };
+
+        // Ursprüngliche Zwischenablage des Nutzers wiederherstellen
+        if let Some(saved) = saved_clipboard {
+            if let Err(e) = copy_to_clipboard(saved, app_handle.clone(), None) {
+                println!("⚠️ Konnte ursprüngliche Zwischenablage nicht wiederherstellen: {}", e);
+            }
         }
+
+        result
     }
-    
+
     #[cfg(target_os = "windows")]
     {
+        let saved_clipboard = read_clipboard(app_handle.clone(), None).ok();
+
         // Windows: Ctrl+C senden
         let script = r#"
             Add-Type -AssemblyName System.Windows.Forms
             [System.Windows.Forms.SendKeys]::SendWait("^c")
         "#;
-        
-        match std::process::Command::new("powershell")
+
+        let result = match std::process::Command::new("powershell")
             .arg("-Command")
             .arg(script)
             .output()
@@ -105,7 +226,13 @@ fn auto_copy_selection() -> Result<String, String> {
             Ok(output) => {
                 if output.status.success() {
                     println!("✅ Successfully sent Ctrl+C on Windows");
-                    Ok("Markierter Text automatisch kopiert".to_string())
+                    read_clipboard(app_handle.clone(), None).and_then(|text| {
+                        if saved_clipboard.as_deref() == Some(text.as_str()) {
+                            Err("Keine Selection vorhanden, Zwischenablage unverändert".to_string())
+                        } else {
+                            Ok(text)
+                        }
+                    })
                 } else {
                     let error = String::from_utf8_lossy(&output.stderr);
                     println!("⚠️ PowerShell warning: {}", error);
@@ -116,16 +243,282 @@ fn auto_copy_selection() -> Result<String, String> {
                 println!("❌ PowerShell failed: {:?}", e);
                 Err(format!("Fehler beim automatischen Kopieren: {}", e))
             }
+        };
+
+        if let Some(saved) = saved_clipboard {
+            if let Err(e) = copy_to_clipboard(saved, app_handle.clone(), None) {
+                println!("⚠️ Konnte ursprüngliche Zwischenablage nicht wiederherstellen: {}", e);
+            }
         }
+
+        result
     }
-    
+
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     {
+        let _ = app_handle;
         println!("ℹ️ Auto-copy not implemented for this platform");
         Err("Auto-copy nicht verfügbar auf diesem System".to_string())
     }
 }
 
+// Tauri command um zu prüfen (und optional anzufordern), ob die App als
+// vertrauenswürdig für die Accessibility-API eingestuft ist. Ohne Trust
+// schlagen `get_selected_text` und `auto_copy_selection` stillschweigend fehl.
+#[tauri::command]
+fn query_accessibility_permissions(prompt: bool) -> Result<bool, String> {
+    println!("🔐 Prüfe Accessibility-Berechtigung (prompt={})...", prompt);
+
+    #[cfg(target_os = "macos")]
+    {
+        let trusted = if prompt {
+            macos_accessibility_client::accessibility::application_is_trusted_with_prompt()
+        } else {
+            macos_accessibility_client::accessibility::application_is_trusted()
+        };
+
+        if trusted {
+            println!("✅ App ist für Accessibility-API vertrauenswürdig");
+        } else {
+            println!("⚠️ App ist NICHT für Accessibility-API vertrauenswürdig (System Settings → Privacy & Security → Accessibility)");
+        }
+
+        Ok(trusted)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Ok(true)
+    }
+}
+
+// Sendet Ctrl+C auf Linux über `xdotool`, da es (anders als macOS/Windows) kein
+// eingebautes Keystroke-API gibt. Wird nur erreicht, wenn die PRIMARY-Selection
+// leer/unlesbar war – siehe `clipboard_roundtrip_capture`.
+#[cfg(target_os = "linux")]
+fn send_copy_keystroke() -> Result<(), String> {
+    std::process::Command::new("xdotool")
+        .args(["key", "--clearmodifiers", "ctrl+c"])
+        .output()
+        .map_err(|e| format!("xdotool nicht verfügbar: {}", e))
+        .and_then(|output| {
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(format!("xdotool fehlgeschlagen: {}", String::from_utf8_lossy(&output.stderr)))
+            }
+        })
+}
+
+// Copy-Keystroke für Plattformen ohne natives Accessibility-Backend und ohne
+// `xdotool`: sendet nichts und liefert immer einen Fehler, sodass
+// `clipboard_roundtrip_capture` dort ohne Ergebnis endet. macOS und Windows
+// laufen stattdessen über das save-and-restore `auto_copy_selection` (siehe
+// `get_selected_text`); nur auf macOS ist dabei der "funk"-Sound gemutet und
+// die eigene App im Vordergrund wird übersprungen.
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn send_copy_keystroke() -> Result<(), String> {
+    Err("Copy-Keystroke auf diesem Betriebssystem nicht implementiert".to_string())
+}
+
+// Generischer Clipboard-Fallback für Plattformen ohne `auto_copy_selection`-Pfad
+// (aktuell Linux, nachdem die PRIMARY-Selection leer ist): Zwischenablage
+// sichern, Copy-Keystroke senden, bis zu ~100ms auf eine geänderte
+// Zwischenablage pollen, danach den ursprünglichen Inhalt wiederherstellen.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn clipboard_roundtrip_capture(app_handle: tauri::AppHandle) -> Result<String, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let saved = app_handle.clipboard().read_text().unwrap_or_default();
+
+    send_copy_keystroke()?;
+
+    let poll_interval = std::time::Duration::from_millis(10);
+    let deadline = std::time::Instant::now() + std::time::Duration::from_millis(100);
+    let mut captured = String::new();
+
+    while std::time::Instant::now() < deadline {
+        if let Ok(text) = app_handle.clipboard().read_text() {
+            if text != saved && !text.trim().is_empty() {
+                captured = text;
+                break;
+            }
+        }
+        std::thread::sleep(poll_interval);
+    }
+
+    if let Err(e) = app_handle.clipboard().write_text(saved) {
+        println!("⚠️ Konnte ursprüngliche Zwischenablage nicht wiederherstellen: {}", e);
+    }
+
+    if captured.is_empty() {
+        Err("Keine neue Selection über Clipboard-Roundtrip erkannt".to_string())
+    } else {
+        Ok(captured)
+    }
+}
+
+// Tauri command, das Selection-Erfassung plattformübergreifend vereinheitlicht:
+// zuerst der native Accessibility-Pfad (AX auf macOS, UI Automation TextPattern
+// auf Windows, PRIMARY-Selection auf Linux), bei Fehlschlag oder leerer
+// Selection Fallback auf die Zwischenablage.
+#[tauri::command]
+fn get_selected_text(app_handle: tauri::AppHandle) -> Result<SelectionResult, String> {
+    println!("🔎 Erfasse Selection (plattformübergreifend)...");
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(text) = macos_ax::read_selected_text() {
+            if !text.is_empty() {
+                println!("✅ Selection via Accessibility API (macOS): {} Zeichen", text.len());
+                return Ok(SelectionResult { text, method: "accessibility".to_string() });
+            }
+        }
+
+        // Accessibility liefert z.B. in vielen Web-Views keine Selection – Fallback
+        // auf `auto_copy_selection`, das den Keystroke gemutet sendet, die
+        // Zwischenablage wiederherstellt und das eigene Fenster überspringt.
+        println!("ℹ️ Accessibility API liefert keine Selection, Fallback auf auto_copy_selection");
+        return auto_copy_selection(app_handle)
+            .map(|text| SelectionResult { text, method: "clipboard".to_string() });
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(text) = windows_ax::read_selected_text() {
+            if !text.is_empty() {
+                println!("✅ Selection via UI Automation (Windows): {} Zeichen", text.len());
+                return Ok(SelectionResult { text, method: "accessibility".to_string() });
+            }
+        }
+
+        println!("ℹ️ UI Automation liefert keine Selection, Fallback auf auto_copy_selection");
+        return auto_copy_selection(app_handle)
+            .map(|text| SelectionResult { text, method: "clipboard".to_string() });
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Unter X11/Wayland landet markierter Text ohne jedes Kopieren bereits
+        // in der PRIMARY-Selection – kein Keystroke nötig.
+        let provider = linux_clipboard::detect_provider();
+        match provider.get_contents(linux_clipboard::ClipboardType::Selection) {
+            Ok(text) if !text.is_empty() => {
+                println!("✅ Selection via PRIMARY-Selection (Linux): {} Zeichen", text.len());
+                return Ok(SelectionResult { text, method: "primary-selection".to_string() });
+            }
+            Ok(_) => println!("ℹ️ PRIMARY-Selection ist leer, Fallback auf Clipboard-Roundtrip"),
+            Err(e) => println!("⚠️ PRIMARY-Selection nicht lesbar ({}), Fallback auf Clipboard-Roundtrip", e),
+        }
+
+        return clipboard_roundtrip_capture(app_handle)
+            .map(|text| SelectionResult { text, method: "clipboard".to_string() });
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        println!("ℹ️ Kein natives Accessibility-Backend verfügbar, Fallback auf Clipboard-Roundtrip");
+        clipboard_roundtrip_capture(app_handle)
+            .map(|text| SelectionResult { text, method: "clipboard".to_string() })
+    }
+}
+
+const CAPTURE_SHORTCUT_FILE: &str = "capture_shortcut.txt";
+
+// Pfad, unter dem der zuletzt registrierte Capture-Shortcut persistiert wird,
+// damit er beim nächsten App-Start automatisch wieder aktiv ist.
+fn capture_shortcut_path(app_handle: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    use tauri::Manager;
+
+    let dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Konnte Config-Verzeichnis nicht ermitteln: {}", e))?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Konnte Config-Verzeichnis nicht anlegen: {}", e))?;
+    Ok(dir.join(CAPTURE_SHORTCUT_FILE))
+}
+
+// Prüft, ob eines unserer eigenen Fenster gerade fokussiert ist, damit der
+// Capture-Shortcut nicht die eigene Selection abgreift.
+fn is_own_window_focused(app_handle: &tauri::AppHandle) -> bool {
+    use tauri::Manager;
+
+    app_handle
+        .webview_windows()
+        .values()
+        .any(|window| window.is_focused().unwrap_or(false))
+}
+
+// Führt die eigentliche Capture-Pipeline aus: Selection lesen (Accessibility
+// oder Clipboard-Fallback) und das Ergebnis per Event ans Frontend senden.
+fn run_capture_pipeline(app_handle: tauri::AppHandle) {
+    use tauri::Emitter;
+
+    if is_own_window_focused(&app_handle) {
+        println!("ℹ️ MeinWort-Pro ist selbst fokussiert, Capture-Shortcut wird ignoriert");
+        return;
+    }
+
+    match get_selected_text(app_handle.clone()) {
+        Ok(result) => {
+            println!("✅ Selection via Shortcut erfasst ({}): {} Zeichen", result.method, result.text.len());
+            if let Err(e) = app_handle.emit("selection-captured", result) {
+                println!("❌ Konnte selection-captured Event nicht senden: {:?}", e);
+            }
+        }
+        Err(e) => println!("⚠️ Konnte Selection über Shortcut nicht erfassen: {}", e),
+    }
+}
+
+// Tauri command um einen global konfigurierbaren Shortcut zu registrieren, der
+// die komplette Capture-Pipeline auslöst und per `selection-captured` Event
+// an das Frontend meldet.
+#[tauri::command]
+fn register_capture_shortcut(app_handle: tauri::AppHandle, accelerator: String) -> Result<String, String> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+    println!("⌨️ Registriere globalen Capture-Shortcut: {}", accelerator);
+
+    // Zuvor registrierten Shortcut entfernen, bevor ein neuer gesetzt wird
+    let _ = app_handle.global_shortcut().unregister_all();
+
+    app_handle
+        .global_shortcut()
+        .on_shortcut(accelerator.as_str(), move |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                run_capture_pipeline(app.clone());
+            }
+        })
+        .map_err(|e| format!("Konnte Shortcut nicht registrieren: {}", e))?;
+
+    let path = capture_shortcut_path(&app_handle)?;
+    std::fs::write(&path, &accelerator)
+        .map_err(|e| format!("Konnte Shortcut nicht speichern: {}", e))?;
+
+    println!("✅ Capture-Shortcut {} registriert", accelerator);
+    Ok(format!("Shortcut {} registriert", accelerator))
+}
+
+// Tauri command zum Entfernen des aktuell registrierten Capture-Shortcuts.
+#[tauri::command]
+fn unregister_capture_shortcut(app_handle: tauri::AppHandle) -> Result<String, String> {
+    use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+    println!("⌨️ Entferne globalen Capture-Shortcut");
+
+    app_handle
+        .global_shortcut()
+        .unregister_all()
+        .map_err(|e| format!("Konnte Shortcut nicht entfernen: {}", e))?;
+
+    if let Ok(path) = capture_shortcut_path(&app_handle) {
+        let _ = std::fs::remove_file(path);
+    }
+
+    Ok("Capture-Shortcut entfernt".to_string())
+}
+
 // Tauri command zum Clearen aller anderen Text-Selections
 #[tauri::command]
 fn clear_other_selections() -> Result<String, String> {
@@ -218,12 +611,23 @@ fn clear_other_selections() -> Result<String, String> {
 pub fn run() {
   tauri::Builder::default()
     .plugin(tauri_plugin_clipboard_manager::init())
-    .invoke_handler(tauri::generate_handler![copy_to_clipboard, read_clipboard, auto_copy_selection, clear_other_selections])
+    .invoke_handler(tauri::generate_handler![copy_to_clipboard, read_clipboard, auto_copy_selection, get_selected_text, query_accessibility_permissions, register_capture_shortcut, unregister_capture_shortcut, clear_other_selections])
     .setup(|app| {
       // Initialize global shortcut plugin (desktop only)
       #[cfg(desktop)]
-      app.handle().plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
-      
+      {
+        app.handle().plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
+
+        // Zuletzt registrierten Capture-Shortcut wiederherstellen
+        if let Ok(path) = capture_shortcut_path(&app.handle()) {
+          if let Ok(accelerator) = std::fs::read_to_string(&path) {
+            if let Err(e) = register_capture_shortcut(app.handle().clone(), accelerator) {
+              println!("⚠️ Konnte gespeicherten Capture-Shortcut nicht wiederherstellen: {}", e);
+            }
+          }
+        }
+      }
+
       if cfg!(debug_assertions) {
         app.handle().plugin(
           tauri_plugin_log::Builder::default()
@@ -0,0 +1,205 @@
+//! Pluggable Clipboard-Backend für Linux. `tauri_plugin_clipboard_manager`
+//! deckt nur die CLIPBOARD-Selection ab; markierter Text landet unter X11
+//! und Wayland aber zuerst in der PRIMARY-Selection. Dieses Modul wählt
+//! abhängig von der Session-Umgebung ein Backend und erlaubt den Zugriff auf
+//! beide Selections. Die Wahl trifft `detect_provider` bei jedem Aufruf neu;
+//! sie wird nicht beim Start festgelegt und nicht zwischengespeichert.
+
+#![cfg(target_os = "linux")]
+
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+
+/// Welche Art von Clipboard angesprochen werden soll. `Selection` ist die
+/// X11/Wayland PRIMARY-Selection, in der markierter Text ohne explizites
+/// Kopieren landet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardType {
+    Clipboard,
+    Selection,
+}
+
+/// Backend-Abstraktion für Linux-Clipboard-Zugriff, damit Wayland und X11
+/// austauschbar hinter derselben Schnittstelle stehen. Die CLI-Tools
+/// (`wl-copy`/`wl-paste`, `xclip`/`xsel`) sind kein eigenes Backend, sondern
+/// der interne Fallback des jeweiligen Backends.
+pub trait ClipboardProvider: Send + Sync {
+    fn get_contents(&self, kind: ClipboardType) -> Result<String, String>;
+    fn set_contents(&self, text: String, kind: ClipboardType) -> Result<(), String>;
+}
+
+/// Erkennt die laufende Session-Umgebung und wählt das passende Backend:
+/// bevorzugt `wl-clipboard-rs` unter Wayland, `x11-clipboard` unter X11.
+pub fn detect_provider() -> Box<dyn ClipboardProvider> {
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        println!("🐧 Wayland-Session erkannt, verwende wl-clipboard Backend");
+        Box::new(WaylandClipboard)
+    } else {
+        println!("🐧 X11-Session erkannt, verwende x11-clipboard Backend");
+        Box::new(X11Clipboard)
+    }
+}
+
+struct WaylandClipboard;
+
+impl ClipboardProvider for WaylandClipboard {
+    fn get_contents(&self, kind: ClipboardType) -> Result<String, String> {
+        use wl_clipboard_rs::paste::{get_contents, ClipboardType as WlType, MimeType, Seat};
+
+        let wl_kind = match kind {
+            ClipboardType::Clipboard => WlType::Regular,
+            ClipboardType::Selection => WlType::Primary,
+        };
+
+        match get_contents(wl_kind, Seat::Unspecified, MimeType::Text) {
+            Ok((mut pipe, _)) => {
+                let mut contents = String::new();
+                pipe.read_to_string(&mut contents)
+                    .map_err(|e| format!("Konnte wl-clipboard-rs Pipe nicht lesen: {}", e))?;
+                Ok(contents)
+            }
+            Err(e) => {
+                println!("⚠️ wl-clipboard-rs fehlgeschlagen ({}), Fallback auf wl-paste", e);
+                run_paste_tool("wl-paste", paste_tool_args(kind))
+            }
+        }
+    }
+
+    fn set_contents(&self, text: String, kind: ClipboardType) -> Result<(), String> {
+        use wl_clipboard_rs::copy::{ClipboardType as WlType, MimeType, Options, Seat, Source};
+
+        let wl_kind = match kind {
+            ClipboardType::Clipboard => WlType::Regular,
+            ClipboardType::Selection => WlType::Primary,
+        };
+
+        let mut options = Options::new();
+        options.clipboard(wl_kind);
+        options.seat(Seat::Unspecified);
+
+        match options.copy(Source::Bytes(text.clone().into_bytes().into()), MimeType::Text) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                println!("⚠️ wl-clipboard-rs fehlgeschlagen ({}), Fallback auf wl-copy", e);
+                run_copy_tool("wl-copy", copy_tool_args(kind), text)
+            }
+        }
+    }
+}
+
+struct X11Clipboard;
+
+impl ClipboardProvider for X11Clipboard {
+    fn get_contents(&self, kind: ClipboardType) -> Result<String, String> {
+        match x11_clipboard::Clipboard::new() {
+            Ok(clipboard) => {
+                let atom = match kind {
+                    ClipboardType::Clipboard => clipboard.setter.atoms.clipboard,
+                    ClipboardType::Selection => clipboard.setter.atoms.primary,
+                };
+
+                clipboard
+                    .load(atom, clipboard.getter.atoms.utf8_string, clipboard.getter.atoms.property, std::time::Duration::from_secs(1))
+                    .map_err(|e| format!("x11-clipboard fehlgeschlagen: {:?}", e))
+                    .and_then(|buf| String::from_utf8(buf).map_err(|e| format!("Ungültiges UTF-8: {}", e)))
+            }
+            Err(e) => {
+                println!("⚠️ x11-clipboard fehlgeschlagen ({:?}), Fallback auf xclip/xsel", e);
+                run_paste_tool("xclip", xclip_paste_args(kind))
+                    .or_else(|_| run_paste_tool("xsel", xsel_args(kind)))
+            }
+        }
+    }
+
+    fn set_contents(&self, text: String, kind: ClipboardType) -> Result<(), String> {
+        match x11_clipboard::Clipboard::new() {
+            Ok(clipboard) => {
+                let atom = match kind {
+                    ClipboardType::Clipboard => clipboard.setter.atoms.clipboard,
+                    ClipboardType::Selection => clipboard.setter.atoms.primary,
+                };
+
+                clipboard
+                    .store(atom, clipboard.setter.atoms.utf8_string, text.clone().into_bytes())
+                    .map_err(|e| format!("x11-clipboard fehlgeschlagen: {:?}", e))
+            }
+            Err(e) => {
+                println!("⚠️ x11-clipboard fehlgeschlagen ({:?}), Fallback auf xclip/xsel", e);
+                run_copy_tool("xclip", xclip_copy_args(kind), text.clone())
+                    .or_else(|_| run_copy_tool("xsel", xsel_args(kind), text))
+            }
+        }
+    }
+}
+
+fn paste_tool_args(kind: ClipboardType) -> Vec<&'static str> {
+    match kind {
+        ClipboardType::Clipboard => vec![],
+        ClipboardType::Selection => vec!["--primary"],
+    }
+}
+
+fn copy_tool_args(kind: ClipboardType) -> Vec<&'static str> {
+    paste_tool_args(kind)
+}
+
+fn xclip_paste_args(kind: ClipboardType) -> Vec<&'static str> {
+    match kind {
+        ClipboardType::Clipboard => vec!["-selection", "clipboard", "-o"],
+        ClipboardType::Selection => vec!["-selection", "primary", "-o"],
+    }
+}
+
+fn xclip_copy_args(kind: ClipboardType) -> Vec<&'static str> {
+    match kind {
+        ClipboardType::Clipboard => vec!["-selection", "clipboard"],
+        ClipboardType::Selection => vec!["-selection", "primary"],
+    }
+}
+
+fn xsel_args(kind: ClipboardType) -> Vec<&'static str> {
+    match kind {
+        ClipboardType::Clipboard => vec!["--clipboard"],
+        ClipboardType::Selection => vec!["--primary"],
+    }
+}
+
+fn run_paste_tool(tool: &str, args: Vec<&str>) -> Result<String, String> {
+    std::process::Command::new(tool)
+        .args(&args)
+        .output()
+        .map_err(|e| format!("{} nicht verfügbar: {}", tool, e))
+        .and_then(|output| {
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            } else {
+                Err(format!("{} fehlgeschlagen: {}", tool, String::from_utf8_lossy(&output.stderr)))
+            }
+        })
+}
+
+fn run_copy_tool(tool: &str, args: Vec<&str>, text: String) -> Result<(), String> {
+    let mut child = Command::new(tool)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("{} nicht verfügbar: {}", tool, e))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| format!("Konnte stdin von {} nicht öffnen", tool))?
+        .write_all(text.as_bytes())
+        .map_err(|e| format!("Konnte Text nicht an {} senden: {}", tool, e))?;
+
+    child
+        .wait()
+        .map_err(|e| format!("{} fehlgeschlagen: {}", tool, e))
+        .and_then(|status| {
+            if status.success() {
+                Ok(())
+            } else {
+                Err(format!("{} beendete sich mit Fehler", tool))
+            }
+        })
+}
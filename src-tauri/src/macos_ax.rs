@@ -0,0 +1,56 @@
+//! Low-Level-Zugriff auf die macOS Accessibility API, um den aktuell
+//! markierten Text direkt vom fokussierten UI-Element zu lesen, ohne
+//! die Zwischenablage zu verändern.
+
+#![cfg(target_os = "macos")]
+
+use accessibility_sys::{
+    kAXFocusedUIElementAttribute, kAXSelectedTextAttribute, AXUIElementCopyAttributeValue,
+    AXUIElementCreateSystemWide, AXUIElementRef,
+};
+use core_foundation::base::{CFRelease, CFTypeRef, TCFType};
+use core_foundation::string::{CFString, CFStringRef};
+
+/// Liest den markierten Text des aktuell fokussierten UI-Elements über die
+/// Accessibility API.
+///
+/// Gibt `Ok(String::new())` zurück, wenn das fokussierte Element kein
+/// `kAXSelectedTextAttribute` unterstützt (z.B. viele Web-Views) – in dem
+/// Fall soll der Aufrufer auf den Cmd+C-Fallback zurückfallen.
+pub fn read_selected_text() -> Result<String, String> {
+    unsafe {
+        let system_wide: AXUIElementRef = AXUIElementCreateSystemWide();
+        if system_wide.is_null() {
+            return Err("Konnte System-Wide AXUIElement nicht erstellen".to_string());
+        }
+
+        let mut focused_element: CFTypeRef = std::ptr::null();
+        let result = AXUIElementCopyAttributeValue(
+            system_wide,
+            CFString::new(kAXFocusedUIElementAttribute).as_concrete_TypeRef(),
+            &mut focused_element,
+        );
+        CFRelease(system_wide as CFTypeRef);
+
+        if result != 0 || focused_element.is_null() {
+            return Err("Kein fokussiertes Element gefunden".to_string());
+        }
+        let focused_element = focused_element as AXUIElementRef;
+
+        let mut selected_text: CFTypeRef = std::ptr::null();
+        let result = AXUIElementCopyAttributeValue(
+            focused_element,
+            CFString::new(kAXSelectedTextAttribute).as_concrete_TypeRef(),
+            &mut selected_text,
+        );
+        CFRelease(focused_element as CFTypeRef);
+
+        if result != 0 || selected_text.is_null() {
+            // Kein Selected-Text-Attribute auf diesem Element (üblich in Web-Views).
+            return Ok(String::new());
+        }
+
+        let cf_string = CFString::wrap_under_create_rule(selected_text as CFStringRef);
+        Ok(cf_string.to_string())
+    }
+}
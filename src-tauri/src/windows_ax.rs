@@ -0,0 +1,55 @@
+//! Low-Level-Zugriff auf die Windows UI Automation API, um den markierten
+//! Text des fokussierten Elements über das `TextPattern` zu lesen, ohne
+//! die Zwischenablage zu berühren.
+
+#![cfg(target_os = "windows")]
+
+use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED};
+use windows::Win32::UI::Accessibility::{
+    CUIAutomation, IUIAutomation, IUIAutomationTextPattern, UIA_TextPatternId,
+};
+
+/// Liest den markierten Text des aktuell fokussierten UI-Elements über
+/// `TextPattern::GetSelection`.
+///
+/// Gibt `Ok(String::new())` zurück, wenn das Element kein `TextPattern`
+/// unterstützt oder keine Selection hat – in dem Fall soll der Aufrufer auf
+/// den Clipboard-Roundtrip zurückfallen.
+pub fn read_selected_text() -> Result<String, String> {
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+        let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| format!("Konnte IUIAutomation nicht erstellen: {e}"))?;
+
+        let focused = automation
+            .GetFocusedElement()
+            .map_err(|e| format!("Kein fokussiertes Element gefunden: {e}"))?;
+
+        let pattern = match focused.GetCurrentPattern(UIA_TextPatternId) {
+            Ok(p) => p,
+            Err(_) => return Ok(String::new()),
+        };
+        let text_pattern: IUIAutomationTextPattern = match pattern.cast() {
+            Ok(p) => p,
+            Err(_) => return Ok(String::new()),
+        };
+
+        let ranges = text_pattern
+            .GetSelection()
+            .map_err(|e| format!("Keine Selection verfügbar: {e}"))?;
+
+        if ranges.Length().unwrap_or(0) == 0 {
+            return Ok(String::new());
+        }
+
+        let range = ranges
+            .GetElement(0)
+            .map_err(|e| format!("Konnte Selection-Range nicht lesen: {e}"))?;
+        let text = range
+            .GetText(-1)
+            .map_err(|e| format!("Konnte Text aus Range nicht lesen: {e}"))?;
+
+        Ok(text.to_string())
+    }
+}